@@ -0,0 +1,347 @@
+use std::collections::{BinaryHeap, HashMap};
+use std::cmp::Ordering;
+
+#[derive(Debug, Eq, PartialEq)]
+struct HuffmanNode {
+    frequency: usize,
+    character: Option<u8>,
+    left: Option<Box<HuffmanNode>>,
+    right: Option<Box<HuffmanNode>>,
+}
+
+impl HuffmanNode {
+    fn new(frequency: usize, character: Option<u8>) -> Self {
+        HuffmanNode {
+            frequency,
+            character,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+impl Ord for HuffmanNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.frequency.cmp(&self.frequency)
+    }
+}
+
+impl PartialOrd for HuffmanNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn build_huffman_tree(frequencies: &HashMap<u8, usize>) -> Option<HuffmanNode> {
+    let mut heap = BinaryHeap::new();
+
+    for (&byte, &frequency) in frequencies {
+        heap.push(HuffmanNode::new(frequency, Some(byte)));
+    }
+
+    while heap.len() > 1 {
+        let left = heap.pop().unwrap();
+        let right = heap.pop().unwrap();
+
+        let mut internal_node = HuffmanNode::new(left.frequency + right.frequency, None);
+        internal_node.left = Some(Box::new(left));
+        internal_node.right = Some(Box::new(right));
+
+        heap.push(internal_node);
+    }
+
+    heap.pop()
+}
+
+/// Collect the bit-length of every symbol's code by walking the tree. Only the
+/// lengths matter for canonical coding, so the shapes of the codes themselves
+/// are discarded.
+fn code_lengths(node: &HuffmanNode, depth: usize, lengths: &mut HashMap<u8, usize>) {
+    if let Some(byte) = node.character {
+        lengths.insert(byte, depth);
+    } else {
+        if let Some(ref left) = node.left {
+            code_lengths(left, depth + 1, lengths);
+        }
+        if let Some(ref right) = node.right {
+            code_lengths(right, depth + 1, lengths);
+        }
+    }
+}
+
+/// The largest code length the canonical coder and the length header support.
+///
+/// Codes are accumulated in a `u64` and lengths are stored as a single byte in
+/// the header, so a length must fit both. With at most 256 symbols a Huffman
+/// code is never longer than 255 bits, so this bound is only reachable under
+/// pathological frequency distributions and is asserted rather than silently
+/// truncated.
+const MAX_CODE_LENGTH: usize = 64;
+
+/// Assign canonical Huffman codes from a table of code lengths.
+///
+/// Symbols are ordered by `(length, value)`, the first code is all zeros, and
+/// each subsequent code is `(prev_code + 1) << (len - prev_len)`. Both encoder
+/// and decoder derive identical codes from the lengths alone.
+///
+/// # Panics
+///
+/// Panics if any code length exceeds [`MAX_CODE_LENGTH`], which would overflow
+/// the `u64` code accumulator.
+fn canonical_codes(lengths: &HashMap<u8, usize>) -> HashMap<u8, String> {
+    let mut symbols: Vec<(u8, usize)> = lengths.iter().map(|(&b, &l)| (b, l)).collect();
+    symbols.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+
+    let mut codes = HashMap::new();
+    let mut code: u64 = 0;
+    let mut prev_len = 0;
+    for (index, &(byte, len)) in symbols.iter().enumerate() {
+        assert!(
+            len <= MAX_CODE_LENGTH,
+            "code length {len} exceeds supported maximum of {MAX_CODE_LENGTH} bits"
+        );
+        if index > 0 {
+            code = (code + 1) << (len - prev_len);
+        }
+        prev_len = len;
+
+        let bits = if len == 0 {
+            String::new()
+        } else {
+            format!("{:0width$b}", code, width = len)
+        };
+        codes.insert(byte, bits);
+    }
+
+    codes
+}
+
+/// Serialize a code-length table as a sparse header: a two-byte symbol count
+/// (big-endian) followed by one `(byte, length)` pair per symbol.
+pub fn emit_length_header(lengths: &HashMap<u8, usize>) -> Vec<u8> {
+    let mut entries: Vec<(&u8, &usize)> = lengths.iter().collect();
+    entries.sort_by_key(|(byte, _)| **byte);
+
+    let count = entries.len();
+    let mut header = Vec::with_capacity(2 + count * 2);
+    header.push((count >> 8) as u8);
+    header.push((count & 0xff) as u8);
+    for (byte, len) in entries {
+        header.push(*byte);
+        header.push(*len as u8);
+    }
+
+    header
+}
+
+/// Parse a header produced by [`emit_length_header`], returning the recovered
+/// code lengths and the number of bytes consumed (where the payload begins).
+pub fn parse_length_header(data: &[u8]) -> (HashMap<u8, usize>, usize) {
+    let mut lengths = HashMap::new();
+    if data.len() < 2 {
+        return (lengths, data.len());
+    }
+
+    let count = ((data[0] as usize) << 8) | data[1] as usize;
+    let mut pos = 2;
+    for _ in 0..count {
+        if pos + 1 >= data.len() {
+            break;
+        }
+        lengths.insert(data[pos], data[pos + 1] as usize);
+        pos += 2;
+    }
+
+    (lengths, pos)
+}
+
+/// Rebuild the Huffman tree from a code table so decoding can walk it.
+///
+/// Each code string traces a path from the root (`'0'` left, `'1'` right) to a
+/// leaf holding the symbol. The degenerate single-symbol table stores an empty
+/// code, in which case the root itself becomes the leaf.
+fn rebuild_tree(codes: &HashMap<u8, String>) -> HuffmanNode {
+    let mut root = HuffmanNode::new(0, None);
+
+    for (&byte, code) in codes {
+        if code.is_empty() {
+            root.character = Some(byte);
+            continue;
+        }
+
+        let mut node = &mut root;
+        for bit in code.chars() {
+            let child = if bit == '0' { &mut node.left } else { &mut node.right };
+            if child.is_none() {
+                *child = Some(Box::new(HuffmanNode::new(0, None)));
+            }
+            node = child.as_mut().unwrap();
+        }
+        node.character = Some(byte);
+    }
+
+    root
+}
+
+/// Pack a string of `'0'`/`'1'` bits into bytes, MSB-first.
+///
+/// The first byte of the returned buffer is the number of padding bits that
+/// were appended to round the payload up to a whole byte, so the decoder knows
+/// how many trailing bits to ignore.
+fn pack_bits(bits: &str) -> Vec<u8> {
+    let pad = (8 - bits.len() % 8) % 8;
+
+    let mut packed = Vec::with_capacity(1 + (bits.len() + pad) / 8);
+    packed.push(pad as u8);
+
+    let mut byte = 0u8;
+    let mut filled = 0u8;
+    for bit in bits.chars() {
+        byte = (byte << 1) | (bit == '1') as u8;
+        filled += 1;
+        if filled == 8 {
+            packed.push(byte);
+            byte = 0;
+            filled = 0;
+        }
+    }
+    if filled > 0 {
+        byte <<= 8 - filled;
+        packed.push(byte);
+    }
+
+    packed
+}
+
+/// Unpack a buffer produced by [`pack_bits`] back into a string of bits,
+/// dropping the padding recorded in the leading count byte.
+fn unpack_bits(data: &[u8]) -> String {
+    if data.is_empty() {
+        return String::new();
+    }
+
+    let pad = data[0] as usize;
+    let payload = &data[1..];
+    let valid = (payload.len() * 8).saturating_sub(pad);
+
+    let mut bits = String::with_capacity(valid);
+    'outer: for &byte in payload {
+        for shift in (0..8).rev() {
+            if bits.len() == valid {
+                break 'outer;
+            }
+            bits.push(if (byte >> shift) & 1 == 1 { '1' } else { '0' });
+        }
+    }
+
+    bits
+}
+
+/// A canonical Huffman code derived from a sample of data.
+///
+/// Construct one with [`Huffman::new_from_data`], then [`compress`](Huffman::compress)
+/// and [`decompress`](Huffman::decompress) with a code table of the same
+/// lengths. The struct owns the frequency table, the per-symbol code lengths
+/// and the canonical code table derived from them.
+pub struct Huffman {
+    frequencies: HashMap<u8, usize>,
+    lengths: HashMap<u8, usize>,
+    codes: HashMap<u8, String>,
+}
+
+impl Huffman {
+    /// Build a canonical Huffman code from `data`: count byte frequencies,
+    /// derive code lengths from the resulting tree, then assign canonical codes.
+    pub fn new_from_data(data: &[u8]) -> Huffman {
+        let mut frequencies = HashMap::new();
+        for &byte in data {
+            *frequencies.entry(byte).or_insert(0) += 1;
+        }
+
+        let mut lengths = HashMap::new();
+        if let Some(ref root) = build_huffman_tree(&frequencies) {
+            code_lengths(root, 0, &mut lengths);
+        }
+
+        let codes = canonical_codes(&lengths);
+
+        Huffman { frequencies, lengths, codes }
+    }
+
+    /// Reconstruct a `Huffman` from a code-length table, e.g. one parsed from a
+    /// compressed payload's header with [`parse_length_header`].
+    pub fn from_lengths(lengths: HashMap<u8, usize>) -> Huffman {
+        let codes = canonical_codes(&lengths);
+        Huffman {
+            frequencies: HashMap::new(),
+            lengths,
+            codes,
+        }
+    }
+
+    /// The canonical code table mapping each byte to its bit string.
+    pub fn codes(&self) -> &HashMap<u8, String> {
+        &self.codes
+    }
+
+    /// The per-symbol code lengths, which is all that needs to be stored to
+    /// reconstruct the code table.
+    pub fn code_lengths(&self) -> &HashMap<u8, usize> {
+        &self.lengths
+    }
+
+    /// The byte frequency table the code was built from (empty when the
+    /// `Huffman` was reconstructed with [`from_lengths`](Huffman::from_lengths)).
+    pub fn frequencies(&self) -> &HashMap<u8, usize> {
+        &self.frequencies
+    }
+
+    /// Encode `data` into a packed bit buffer using this code table.
+    ///
+    /// When the data contains a single distinct byte its code is empty, so we
+    /// still emit one bit per occurrence; that lets the decoder recover the
+    /// count.
+    pub fn compress(&self, data: &[u8]) -> Vec<u8> {
+        let mut bits = String::new();
+        for byte in data {
+            let code = &self.codes[byte];
+            if code.is_empty() {
+                bits.push('0');
+            } else {
+                bits.push_str(code);
+            }
+        }
+
+        pack_bits(&bits)
+    }
+
+    /// Decode a buffer produced by [`compress`](Huffman::compress) back into the
+    /// original bytes by walking the Huffman tree one bit at a time.
+    pub fn decompress(&self, compressed: &[u8]) -> Vec<u8> {
+        let root = rebuild_tree(&self.codes);
+        let bits = unpack_bits(compressed);
+
+        let mut result = Vec::new();
+
+        // Single-symbol tree: the root is a leaf and every code is empty, so
+        // emit the symbol once for each bit that was written.
+        if let Some(byte) = root.character {
+            result.extend(bits.chars().map(|_| byte));
+            return result;
+        }
+
+        let mut node = &root;
+        for bit in bits.chars() {
+            node = match if bit == '0' { node.left.as_deref() } else { node.right.as_deref() } {
+                Some(next) => next,
+                None => break,
+            };
+            if let Some(byte) = node.character {
+                result.push(byte);
+                node = &root;
+            }
+        }
+
+        result
+    }
+}
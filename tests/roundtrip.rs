@@ -0,0 +1,45 @@
+use huffman::{emit_length_header, parse_length_header, Huffman};
+use proptest::prelude::*;
+
+/// Compress `data` and decompress it again through the full on-disk format
+/// (length header + packed payload), mirroring what the CLI writes and reads.
+fn roundtrip(data: &[u8]) -> Vec<u8> {
+    let huffman = Huffman::new_from_data(data);
+
+    let mut blob = emit_length_header(huffman.code_lengths());
+    blob.extend_from_slice(&huffman.compress(data));
+
+    let (lengths, consumed) = parse_length_header(&blob);
+    Huffman::from_lengths(lengths).decompress(&blob[consumed..])
+}
+
+#[test]
+fn roundtrips_empty_input() {
+    assert_eq!(roundtrip(&[]), Vec::<u8>::new());
+}
+
+#[test]
+fn roundtrips_single_repeated_symbol() {
+    let data = vec![0x41; 37];
+    assert_eq!(roundtrip(&data), data);
+}
+
+#[test]
+fn roundtrips_exactly_two_symbols() {
+    let data = b"aababbbaab".to_vec();
+    assert_eq!(roundtrip(&data), data);
+}
+
+proptest! {
+    #[test]
+    fn roundtrips_arbitrary_bytes(data in proptest::collection::vec(any::<u8>(), 0..1024)) {
+        prop_assert_eq!(roundtrip(&data), data);
+    }
+
+    // Skew towards the degenerate low-cardinality inputs (zero, one or two
+    // distinct symbols) that expose the single-node-tree and empty-code bugs.
+    #[test]
+    fn roundtrips_low_cardinality(data in proptest::collection::vec(0u8..2, 0..256)) {
+        prop_assert_eq!(roundtrip(&data), data);
+    }
+}